@@ -15,11 +15,7 @@
 
 use std::{error::Error, io};
 
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
 use itertools::Itertools;
 use ratatui::{prelude::*, widgets::*};
 use style::palette::tailwind;
@@ -69,10 +65,6 @@ struct Data {
 }
 
 impl Data {
-    fn ref_array(&self) -> [&String; 3] {
-        [&self.name, &self.address, &self.email]
-    }
-
     fn name(&self) -> &str {
         &self.name
     }
@@ -99,7 +91,9 @@ impl App {
     fn new() -> App {
         let data_vec = generate_fake_names();
         App {
-            state: TableState::default().with_selected(0),
+            state: TableState::default()
+                .with_selected(0)
+                .with_scroll_padding(1),
             longest_item_lens: constraint_len_calculator(&data_vec),
             scroll_state: ScrollbarState::new((data_vec.len() - 1) * ITEM_HEIGHT),
             colors: TableColors::new(&PALETTES[0]),
@@ -177,25 +171,13 @@ fn generate_fake_names() -> Vec<Data> {
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
-    // setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
+    let backend = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend)?;
+    let mut guard = TerminalGuard::new(&mut terminal)?;
 
     // create app and run it
     let app = App::new();
-    let res = run_app(&mut terminal, app);
-
-    // restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    let res = run_app(&mut guard, app);
 
     if let Err(err) = res {
         println!("{err:?}");
@@ -208,8 +190,8 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
     loop {
         terminal.draw(|f| ui(f, &mut app))?;
 
-        if let Event::Key(key) = event::read()? {
-            if key.kind == KeyEventKind::Press {
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
                 use KeyCode::*;
                 match key.code {
                     Char('q') | Esc => return Ok(()),
@@ -220,6 +202,15 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
                     _ => {}
                 }
             }
+            Event::Mouse(mouse) => match mouse.kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    app.state.select_at_position(mouse.column, mouse.row);
+                }
+                MouseEventKind::ScrollDown => app.next(),
+                MouseEventKind::ScrollUp => app.previous(),
+                _ => {}
+            },
+            _ => {}
         }
     }
 }
@@ -256,12 +247,16 @@ fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
             0 => app.colors.normal_row_color,
             _ => app.colors.alt_row_color,
         };
-        let item = data.ref_array();
-        item.iter()
-            .cloned()
-            .map(|content| Cell::from(Text::from(format!("\n{}\n", content))))
+        let cells = vec![
+            Cell::from(data.name().to_string()),
+            Cell::from(data.address().to_string()),
+            Cell::hyperlink(data.email().to_string(), format!("mailto:{}", data.email())),
+        ];
+        cells
+            .into_iter()
             .collect::<Row>()
             .style(Style::new().fg(app.colors.row_fg).bg(color))
+            .vertical_alignment(VerticalAlignment::Center)
             .height(4)
     });
     let bar = " █ ";
@@ -276,12 +271,7 @@ fn render_table(f: &mut Frame, app: &mut App, area: Rect) {
     )
     .header(header)
     .highlight_style(selected_style)
-    .highlight_symbol(Text::from(vec![
-        "".into(),
-        bar.into(),
-        bar.into(),
-        "".into(),
-    ]))
+    .highlight_symbol(Text::from(bar))
     .bg(app.colors.buffer_bg)
     .highlight_spacing(HighlightSpacing::Always);
     f.render_stateful_widget(t, area, &mut app.state);
@@ -0,0 +1,86 @@
+use std::io::{self, Write};
+
+use crossterm::terminal;
+
+use crate::{buffer::Cell, layout::Rect};
+
+use super::Backend;
+
+/// A [`Backend`] implementation that uses [crossterm] to write to the terminal.
+///
+/// [crossterm]: https://crates.io/crates/crossterm
+pub struct CrosstermBackend<W: Write> {
+    writer: W,
+    /// Whether OSC 8 hyperlinks are emitted for cells carrying a [`Cell::url`]. Disabled by
+    /// default on hosts known to render them poorly, such as VS Code's integrated terminal.
+    hyperlinks: bool,
+}
+
+impl<W: Write> CrosstermBackend<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            hyperlinks: !Self::is_vscode(),
+        }
+    }
+
+    fn is_vscode() -> bool {
+        std::env::var("TERM_PROGRAM").as_deref() == Ok("vscode")
+    }
+
+    /// Overrides whether OSC 8 hyperlinks are emitted, regardless of the detected host.
+    pub fn set_hyperlinks(&mut self, enabled: bool) {
+        self.hyperlinks = enabled;
+    }
+
+    pub fn hyperlinks(&self) -> bool {
+        self.hyperlinks
+    }
+
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+
+const HYPERLINK_RESET: &str = "\x1b]8;;\x1b\\";
+/// SGR reset for the underline attribute. Many terminals underline OSC 8 hyperlink text by
+/// default; closing the link alone doesn't undo that, so without this reset the underline can
+/// bleed onto the cells that follow a link run.
+const UNDERLINE_RESET: &str = "\x1b[24m";
+
+impl<W: Write> Backend for CrosstermBackend<W> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let mut current_url: Option<&str> = None;
+        for (x, y, cell) in content {
+            crossterm::queue!(self.writer, crossterm::cursor::MoveTo(x, y))?;
+            let url = cell.url();
+            if self.hyperlinks && url != current_url {
+                // Close the previous run (if any) before opening or ending a new one.
+                if current_url.is_some() {
+                    write!(self.writer, "{HYPERLINK_RESET}{UNDERLINE_RESET}")?;
+                }
+                if let Some(url) = url {
+                    write!(self.writer, "\x1b]8;;{url}\x1b\\")?;
+                }
+                current_url = url;
+            }
+            write!(self.writer, "{}", cell.symbol())?;
+        }
+        if self.hyperlinks && current_url.is_some() {
+            write!(self.writer, "{HYPERLINK_RESET}{UNDERLINE_RESET}")?;
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        let (width, height) = terminal::size()?;
+        Ok(Rect::new(0, 0, width, height))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
@@ -0,0 +1,23 @@
+//! Backends translate a rendered [`crate::buffer::Buffer`] into the escape sequences a specific
+//! terminal library understands.
+
+mod crossterm;
+
+pub use self::crossterm::CrosstermBackend;
+
+use std::io;
+
+use crate::{buffer::Cell, layout::Rect};
+
+/// A terminal backend, responsible for drawing buffer contents and querying terminal state.
+pub trait Backend {
+    /// Draws the given `(x, y, cell)` triples to the terminal.
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>;
+
+    /// The current size of the terminal.
+    fn size(&self) -> io::Result<Rect>;
+
+    fn flush(&mut self) -> io::Result<()>;
+}
@@ -0,0 +1,149 @@
+//! A [`Buffer`] is the in-memory grid of [`Cell`]s that widgets render into before it is
+//! flushed to the terminal by a [`crate::backend::Backend`].
+
+use crate::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+/// A single cell in the terminal grid: a grapheme, the style used to render it, and the
+/// hyperlink target (if any) carried over from the [`Span`] it was drawn from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    symbol: String,
+    pub fg: Color,
+    pub bg: Color,
+    pub modifier: Modifier,
+    pub url: Option<String>,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            symbol: " ".into(),
+            fg: Color::Reset,
+            bg: Color::Reset,
+            modifier: Modifier::empty(),
+            url: None,
+        }
+    }
+}
+
+impl Cell {
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn set_symbol(&mut self, symbol: &str) -> &mut Self {
+        self.symbol.clear();
+        self.symbol.push_str(symbol);
+        self
+    }
+
+    pub fn set_style(&mut self, style: Style) -> &mut Self {
+        if let Some(fg) = style.fg {
+            self.fg = fg;
+        }
+        if let Some(bg) = style.bg {
+            self.bg = bg;
+        }
+        self.modifier.insert(style.add_modifier);
+        self.modifier.remove(style.sub_modifier);
+        self
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn set_url(&mut self, url: Option<String>) -> &mut Self {
+        self.url = url;
+        self
+    }
+}
+
+/// The buffer backing a single frame: a flat `Vec<Cell>` addressed by `(x, y)`.
+#[derive(Debug, Clone)]
+pub struct Buffer {
+    pub area: Rect,
+    content: Vec<Cell>,
+}
+
+impl Buffer {
+    pub fn empty(area: Rect) -> Self {
+        let size = (area.width as usize) * (area.height as usize);
+        Self {
+            area,
+            content: vec![Cell::default(); size],
+        }
+    }
+
+    fn index_of(&self, x: u16, y: u16) -> usize {
+        let x = x - self.area.x;
+        let y = y - self.area.y;
+        (y as usize) * (self.area.width as usize) + (x as usize)
+    }
+
+    pub fn get(&self, x: u16, y: u16) -> &Cell {
+        &self.content[self.index_of(x, y)]
+    }
+
+    pub fn get_mut(&mut self, x: u16, y: u16) -> &mut Cell {
+        let i = self.index_of(x, y);
+        &mut self.content[i]
+    }
+
+    /// Resets every cell back to its default, ready for the next frame to be drawn into.
+    pub fn reset(&mut self) {
+        for cell in &mut self.content {
+            *cell = Cell::default();
+        }
+    }
+
+    /// Resizes the buffer (and clears it) to cover `area`.
+    pub fn resize(&mut self, area: Rect) {
+        let size = (area.width as usize) * (area.height as usize);
+        self.area = area;
+        self.content = vec![Cell::default(); size];
+    }
+
+    /// Paints `style` onto every cell inside `area`, leaving their symbols untouched.
+    pub fn set_style(&mut self, area: Rect, style: Style) {
+        let x_end = area.right().min(self.area.right());
+        let y_end = area.bottom().min(self.area.bottom());
+        for y in area.top()..y_end {
+            for x in area.left()..x_end {
+                self.get_mut(x, y).set_style(style);
+            }
+        }
+    }
+
+    /// Writes `span` starting at `(x, y)`, truncating at `max_width` columns, carrying its
+    /// style and hyperlink target onto each [`Cell`] it occupies.
+    pub fn set_span(&mut self, x: u16, y: u16, span: &Span, max_width: u16) -> u16 {
+        let mut cx = x;
+        let max_x = x.saturating_add(max_width).min(self.area.right());
+        for ch in span.content.chars() {
+            if cx >= max_x {
+                break;
+            }
+            let cell = self.get_mut(cx, y);
+            cell.set_symbol(&ch.to_string());
+            cell.set_style(span.style);
+            cell.set_url(span.url.as_ref().map(|u| u.to_string()));
+            cx += 1;
+        }
+        cx
+    }
+
+    /// Iterates over every `(x, y, &Cell)` in the buffer, in row-major order.
+    pub fn content(&self) -> impl Iterator<Item = (u16, u16, &Cell)> {
+        let area = self.area;
+        self.content.iter().enumerate().map(move |(i, cell)| {
+            let x = area.x + (i as u16 % area.width);
+            let y = area.y + (i as u16 / area.width);
+            (x, y, cell)
+        })
+    }
+}
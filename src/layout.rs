@@ -0,0 +1,214 @@
+//! Layout primitives shared across buffers and widgets.
+
+/// A simple rectangular area of the terminal, defined by its top-left corner and dimensions.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    pub const fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// The inclusive lower bound of the x-axis, i.e. `self.x`.
+    pub const fn left(self) -> u16 {
+        self.x
+    }
+
+    /// The exclusive upper bound of the x-axis, i.e. `self.x + self.width`.
+    pub const fn right(self) -> u16 {
+        self.x.saturating_add(self.width)
+    }
+
+    /// The inclusive lower bound of the y-axis, i.e. `self.y`.
+    pub const fn top(self) -> u16 {
+        self.y
+    }
+
+    /// The exclusive upper bound of the y-axis, i.e. `self.y + self.height`.
+    pub const fn bottom(self) -> u16 {
+        self.y.saturating_add(self.height)
+    }
+
+    /// Shrinks this rect by `margin` on every side.
+    pub fn inner(self, margin: &Margin) -> Rect {
+        let horizontal = margin.horizontal.saturating_mul(2);
+        let vertical = margin.vertical.saturating_mul(2);
+        if self.width < horizontal || self.height < vertical {
+            Rect::new(self.x, self.y, 0, 0)
+        } else {
+            Rect::new(
+                self.x.saturating_add(margin.horizontal),
+                self.y.saturating_add(margin.vertical),
+                self.width.saturating_sub(horizontal),
+                self.height.saturating_sub(vertical),
+            )
+        }
+    }
+}
+
+/// How a block of text shorter than its containing area should be positioned along the
+/// vertical axis, e.g. a [`Cell`](crate::widgets::Cell) whose row is taller than its content.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum VerticalAlignment {
+    #[default]
+    Top,
+    Center,
+    Bottom,
+}
+
+impl VerticalAlignment {
+    /// The number of blank lines to leave above the content given `content_height` lines in an
+    /// area of `available_height` lines.
+    pub fn top_padding(self, available_height: u16, content_height: u16) -> u16 {
+        let slack = available_height.saturating_sub(content_height);
+        match self {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => slack / 2,
+            VerticalAlignment::Bottom => slack,
+        }
+    }
+}
+
+/// Space to trim from each side of a [`Rect`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Margin {
+    pub horizontal: u16,
+    pub vertical: u16,
+}
+
+/// The axis along which a [`Layout`] arranges its constraints.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Direction {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// A sizing rule for one segment of a [`Layout`] split, or one column of a
+/// [`Table`](crate::widgets::Table).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Constraint {
+    /// Exactly this many cells.
+    Length(u16),
+    /// At least this many cells; grows to fill any remaining space.
+    Min(u16),
+    /// At most this many cells.
+    Max(u16),
+    /// This percentage (0-100) of the available space.
+    Percentage(u16),
+}
+
+impl Constraint {
+    /// The size this constraint wants before any remaining space is distributed.
+    const fn base(self, available: u16) -> u16 {
+        match self {
+            Constraint::Length(len) => len,
+            Constraint::Min(min) => min,
+            Constraint::Max(max) => max,
+            Constraint::Percentage(pct) => (available as u32 * pct as u32 / 100) as u16,
+        }
+    }
+}
+
+/// Splits a [`Rect`] into segments along one axis according to a list of [`Constraint`]s.
+///
+/// This is a simplified, non-solver-based layout: each constraint is given its `base` size (see
+/// [`Constraint::base`]), then any space left over in the area is distributed evenly across the
+/// [`Constraint::Min`] segments, which is enough to satisfy the constraint combinations ratatui's
+/// widgets and examples use in practice.
+#[derive(Debug, Default, Clone)]
+pub struct Layout {
+    direction: Direction,
+    constraints: Vec<Constraint>,
+}
+
+impl Layout {
+    pub fn new<I>(direction: Direction, constraints: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Constraint>,
+    {
+        Self {
+            direction,
+            constraints: constraints.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn vertical<I>(constraints: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Constraint>,
+    {
+        Self::new(Direction::Vertical, constraints)
+    }
+
+    pub fn horizontal<I>(constraints: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Constraint>,
+    {
+        Self::new(Direction::Horizontal, constraints)
+    }
+
+    pub fn split(&self, area: Rect) -> Vec<Rect> {
+        let available = match self.direction {
+            Direction::Horizontal => area.width,
+            Direction::Vertical => area.height,
+        };
+
+        let mut sizes: Vec<u16> = self
+            .constraints
+            .iter()
+            .map(|c| c.base(available).min(available))
+            .collect();
+
+        let used: u32 = sizes.iter().map(|&s| s as u32).sum();
+        let slack = (available as u32).saturating_sub(used) as u16;
+        let grow_count = self
+            .constraints
+            .iter()
+            .filter(|c| matches!(c, Constraint::Min(_)))
+            .count()
+            .max(1) as u16;
+        if slack > 0 {
+            let share = slack / grow_count;
+            let mut remainder = slack % grow_count;
+            for (size, constraint) in sizes.iter_mut().zip(&self.constraints) {
+                if matches!(constraint, Constraint::Min(_)) {
+                    *size += share;
+                    if remainder > 0 {
+                        *size += 1;
+                        remainder -= 1;
+                    }
+                }
+            }
+        }
+
+        let mut offset = 0u16;
+        sizes
+            .into_iter()
+            .map(|size| {
+                let rect = match self.direction {
+                    Direction::Horizontal => {
+                        Rect::new(area.x.saturating_add(offset), area.y, size, area.height)
+                    }
+                    Direction::Vertical => {
+                        Rect::new(area.x, area.y.saturating_add(offset), area.width, size)
+                    }
+                };
+                offset += size;
+                rect
+            })
+            .collect()
+    }
+}
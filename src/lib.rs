@@ -0,0 +1,12 @@
+//! Ratatui is a library for building rich terminal user interfaces and dashboards.
+
+pub mod backend;
+pub mod buffer;
+pub mod layout;
+pub mod prelude;
+pub mod style;
+pub mod terminal;
+pub mod text;
+pub mod widgets;
+
+pub use terminal::{Frame, Terminal, TerminalGuard};
@@ -0,0 +1,12 @@
+//! A convenience re-export of the most commonly used types, for glob importing as
+//! `use ratatui::prelude::*;`.
+
+pub use crate::{
+    backend::{Backend, CrosstermBackend},
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Margin, Rect, VerticalAlignment},
+    style::{self, Color, Modifier, Style},
+    terminal::{Frame, Terminal, TerminalGuard},
+    text::{Line, Span, Text},
+    widgets::{StatefulWidget, Widget},
+};
@@ -0,0 +1,171 @@
+/// A color, either a named/indexed terminal color or a 24-bit RGB triple.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Color {
+    #[default]
+    Reset,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+    DarkGray,
+    LightRed,
+    LightGreen,
+    LightYellow,
+    LightBlue,
+    LightMagenta,
+    LightCyan,
+    White,
+    Rgb(u8, u8, u8),
+    Indexed(u8),
+}
+
+impl Color {
+    /// Maps any [`Color`] to its canonical 24-bit RGB triple. Indexed colors that don't
+    /// correspond to one of the 16 named colors fall back to white, as they depend on the
+    /// terminal's palette.
+    const fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            Color::Reset => (0, 0, 0),
+            Color::Black => (0, 0, 0),
+            Color::Red => (128, 0, 0),
+            Color::Green => (0, 128, 0),
+            Color::Yellow => (128, 128, 0),
+            Color::Blue => (0, 0, 128),
+            Color::Magenta => (128, 0, 128),
+            Color::Cyan => (0, 128, 128),
+            Color::Gray => (192, 192, 192),
+            Color::DarkGray => (128, 128, 128),
+            Color::LightRed => (255, 0, 0),
+            Color::LightGreen => (0, 255, 0),
+            Color::LightYellow => (255, 255, 0),
+            Color::LightBlue => (0, 0, 255),
+            Color::LightMagenta => (255, 0, 255),
+            Color::LightCyan => (0, 255, 255),
+            Color::White => (255, 255, 255),
+            Color::Rgb(r, g, b) => (r, g, b),
+            Color::Indexed(i) if i < 16 => {
+                let named = [
+                    Color::Black, Color::Red, Color::Green, Color::Yellow,
+                    Color::Blue, Color::Magenta, Color::Cyan, Color::Gray,
+                    Color::DarkGray, Color::LightRed, Color::LightGreen, Color::LightYellow,
+                    Color::LightBlue, Color::LightMagenta, Color::LightCyan, Color::White,
+                ];
+                named[i as usize].to_rgb()
+            }
+            Color::Indexed(_) => (255, 255, 255),
+        }
+    }
+
+    /// Builds a [`Color::Rgb`] from hue (`0.0..=360.0`), saturation and lightness
+    /// (`0.0..=1.0`), using the standard sRGB↔HSL conversion.
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        let h = h.rem_euclid(360.0);
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+
+        if s == 0.0 {
+            let v = (l * 255.0).round() as u8;
+            return Color::Rgb(v, v, v);
+        }
+
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let h_prime = h / 60.0;
+        let x = c * (1.0 - (h_prime.rem_euclid(2.0) - 1.0).abs());
+        let (r1, g1, b1) = match h_prime as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c / 2.0;
+        let to_u8 = |v: f64| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Color::Rgb(to_u8(r1), to_u8(g1), to_u8(b1))
+    }
+
+    /// Converts this color to `(hue, saturation, lightness)`, with `hue` in `0.0..=360.0` and
+    /// `saturation`/`lightness` in `0.0..=1.0`. Named/indexed colors are first mapped to their
+    /// canonical RGB value.
+    pub fn to_hsl(self) -> (f64, f64, f64) {
+        let (r, g, b) = self.to_rgb();
+        let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let l = (max + min) / 2.0;
+
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+
+        let s = if l < 0.5 {
+            delta / (max + min)
+        } else {
+            delta / (2.0 - max - min)
+        };
+
+        let h = if max == r {
+            ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        } * 60.0;
+
+        (h, s, l)
+    }
+
+    /// Returns this color lightened by `amount` (`0.0..=1.0`), clamped to fully white.
+    pub fn lighten(self, amount: f64) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h, s, (l + amount).clamp(0.0, 1.0))
+    }
+
+    /// Returns this color darkened by `amount` (`0.0..=1.0`), clamped to fully black.
+    pub fn darken(self, amount: f64) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h, s, (l - amount).clamp(0.0, 1.0))
+    }
+
+    /// Returns this color with its saturation adjusted by `amount` (`-1.0..=1.0`).
+    pub fn saturate(self, amount: f64) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl(h, (s + amount).clamp(0.0, 1.0), l)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_hsl_primary_colors() {
+        assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::Rgb(255, 0, 0));
+        assert_eq!(Color::from_hsl(120.0, 1.0, 0.5), Color::Rgb(0, 255, 0));
+        assert_eq!(Color::from_hsl(240.0, 1.0, 0.5), Color::Rgb(0, 0, 255));
+    }
+
+    #[test]
+    fn to_hsl_round_trips_through_rgb() {
+        let original = Color::Rgb(200, 100, 50);
+        let (h, s, l) = original.to_hsl();
+        assert_eq!(Color::from_hsl(h, s, l), original);
+    }
+
+    #[test]
+    fn lighten_and_darken_move_lightness() {
+        let base = Color::Rgb(100, 100, 100);
+        let (_, _, l) = base.to_hsl();
+        let (_, _, lighter) = base.lighten(0.2).to_hsl();
+        let (_, _, darker) = base.darken(0.2).to_hsl();
+        assert!(lighter > l);
+        assert!(darker < l);
+    }
+}
@@ -0,0 +1,197 @@
+//! Styling primitives (colors, modifiers) used to paint [`crate::buffer::Buffer`] cells.
+
+mod color;
+
+pub use color::Color;
+
+pub mod palette {
+    pub mod tailwind {
+        //! A handful of the Tailwind CSS color palettes, used by examples to build themes.
+
+        use crate::style::Color;
+
+        #[derive(Debug, Clone, Copy)]
+        pub struct Palette {
+            pub c50: Color,
+            pub c100: Color,
+            pub c200: Color,
+            pub c300: Color,
+            pub c400: Color,
+            pub c500: Color,
+            pub c600: Color,
+            pub c700: Color,
+            pub c800: Color,
+            pub c900: Color,
+            pub c950: Color,
+        }
+
+        macro_rules! palette {
+            ($name:ident, [$($shade:expr),+ $(,)?]) => {
+                pub const $name: Palette = {
+                    let [c50, c100, c200, c300, c400, c500, c600, c700, c800, c900, c950] =
+                        [$($shade),+];
+                    Palette {
+                        c50,
+                        c100,
+                        c200,
+                        c300,
+                        c400,
+                        c500,
+                        c600,
+                        c700,
+                        c800,
+                        c900,
+                        c950,
+                    }
+                };
+            };
+        }
+
+        palette!(
+            SLATE,
+            [
+                Color::Rgb(248, 250, 252),
+                Color::Rgb(241, 245, 249),
+                Color::Rgb(226, 232, 240),
+                Color::Rgb(203, 213, 225),
+                Color::Rgb(148, 163, 184),
+                Color::Rgb(100, 116, 139),
+                Color::Rgb(71, 85, 105),
+                Color::Rgb(51, 65, 85),
+                Color::Rgb(30, 41, 59),
+                Color::Rgb(15, 23, 42),
+                Color::Rgb(2, 6, 23),
+            ]
+        );
+
+        palette!(
+            BLUE,
+            [
+                Color::Rgb(239, 246, 255),
+                Color::Rgb(219, 234, 254),
+                Color::Rgb(191, 219, 254),
+                Color::Rgb(147, 197, 253),
+                Color::Rgb(96, 165, 250),
+                Color::Rgb(59, 130, 246),
+                Color::Rgb(37, 99, 235),
+                Color::Rgb(29, 78, 216),
+                Color::Rgb(30, 64, 175),
+                Color::Rgb(30, 58, 138),
+                Color::Rgb(23, 37, 84),
+            ]
+        );
+
+        palette!(
+            EMERALD,
+            [
+                Color::Rgb(236, 253, 245),
+                Color::Rgb(209, 250, 229),
+                Color::Rgb(167, 243, 208),
+                Color::Rgb(110, 231, 183),
+                Color::Rgb(52, 211, 153),
+                Color::Rgb(16, 185, 129),
+                Color::Rgb(5, 150, 105),
+                Color::Rgb(4, 120, 87),
+                Color::Rgb(6, 95, 70),
+                Color::Rgb(6, 78, 59),
+                Color::Rgb(2, 44, 34),
+            ]
+        );
+
+        palette!(
+            INDIGO,
+            [
+                Color::Rgb(238, 242, 255),
+                Color::Rgb(224, 231, 255),
+                Color::Rgb(199, 210, 254),
+                Color::Rgb(165, 180, 252),
+                Color::Rgb(129, 140, 248),
+                Color::Rgb(99, 102, 241),
+                Color::Rgb(79, 70, 229),
+                Color::Rgb(67, 56, 202),
+                Color::Rgb(55, 48, 163),
+                Color::Rgb(49, 46, 129),
+                Color::Rgb(30, 27, 75),
+            ]
+        );
+
+        palette!(
+            RED,
+            [
+                Color::Rgb(254, 242, 242),
+                Color::Rgb(254, 226, 226),
+                Color::Rgb(254, 202, 202),
+                Color::Rgb(252, 165, 165),
+                Color::Rgb(248, 113, 113),
+                Color::Rgb(239, 68, 68),
+                Color::Rgb(220, 38, 38),
+                Color::Rgb(185, 28, 28),
+                Color::Rgb(153, 27, 27),
+                Color::Rgb(127, 29, 29),
+                Color::Rgb(69, 10, 10),
+            ]
+        );
+    }
+}
+
+bitflags::bitflags! {
+    /// Modifier changes the way a piece of text is displayed (bold, italic, etc).
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct Modifier: u16 {
+        const BOLD = 0b0000_0000_0001;
+        const DIM = 0b0000_0000_0010;
+        const ITALIC = 0b0000_0000_0100;
+        const UNDERLINED = 0b0000_0000_1000;
+        const SLOW_BLINK = 0b0000_0001_0000;
+        const RAPID_BLINK = 0b0000_0010_0000;
+        const REVERSED = 0b0000_0100_0000;
+        const HIDDEN = 0b0000_1000_0000;
+        const CROSSED_OUT = 0b0001_0000_0000;
+    }
+}
+
+/// The foreground/background colors and modifiers applied to a piece of text.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub add_modifier: Modifier,
+    pub sub_modifier: Modifier,
+}
+
+impl Style {
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            add_modifier: Modifier::empty(),
+            sub_modifier: Modifier::empty(),
+        }
+    }
+
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    pub fn add_modifier(mut self, modifier: Modifier) -> Self {
+        self.add_modifier.insert(modifier);
+        self
+    }
+
+    /// Merges `other` on top of this style: any field `other` sets overrides this one's.
+    pub fn patch(mut self, other: Style) -> Self {
+        self.fg = other.fg.or(self.fg);
+        self.bg = other.bg.or(self.bg);
+        self.sub_modifier.remove(other.add_modifier);
+        self.add_modifier.insert(other.add_modifier);
+        self.add_modifier.remove(other.sub_modifier);
+        self.sub_modifier.insert(other.sub_modifier);
+        self
+    }
+}
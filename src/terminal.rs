@@ -0,0 +1,185 @@
+//! The [`Terminal`] type coordinates a [`Backend`](crate::backend::Backend) with the buffer it
+//! draws into, plus [`TerminalGuard`] for safe setup/teardown of raw mode and the alternate
+//! screen.
+
+use std::{
+    io,
+    ops::{Deref, DerefMut},
+    panic::{self, PanicHookInfo},
+    sync::Arc,
+};
+
+use crossterm::{
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::{
+    backend::{Backend, CrosstermBackend},
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// The buffer a single call to [`Terminal::draw`] renders widgets into before it is flushed to
+/// the backend.
+pub struct Frame<'a> {
+    buffer: &'a mut Buffer,
+}
+
+impl<'a> Frame<'a> {
+    /// The full area available to draw into this frame.
+    pub fn size(&self) -> Rect {
+        self.buffer.area
+    }
+
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        widget.render(area, self.buffer);
+    }
+
+    pub fn render_stateful_widget<W: StatefulWidget>(
+        &mut self,
+        widget: W,
+        area: Rect,
+        state: &mut W::State,
+    ) {
+        widget.render(area, self.buffer, state);
+    }
+}
+
+/// A thin wrapper coordinating a [`Backend`] and the buffer rendered into it.
+pub struct Terminal<B: Backend> {
+    backend: B,
+    buffer: Buffer,
+}
+
+impl<B: Backend> Terminal<B> {
+    pub fn new(backend: B) -> io::Result<Self> {
+        let area = backend.size()?;
+        Ok(Self {
+            backend,
+            buffer: Buffer::empty(area),
+        })
+    }
+
+    pub fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+
+    /// Draws one frame: `f` is given a [`Frame`] to render widgets into, which is then flushed
+    /// to the backend.
+    pub fn draw<F>(&mut self, f: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut Frame),
+    {
+        let area = self.backend.size()?;
+        if area != self.buffer.area {
+            self.buffer.resize(area);
+        } else {
+            self.buffer.reset();
+        }
+
+        let mut frame = Frame {
+            buffer: &mut self.buffer,
+        };
+        f(&mut frame);
+
+        self.backend.draw(self.buffer.content())?;
+        self.backend.flush()
+    }
+}
+
+/// RAII guard that enters raw mode and the alternate screen on construction, and restores the
+/// terminal on [`Drop`] — including when a panic unwinds through it.
+///
+/// Borrows the [`Terminal`] for its entire lifetime (rather than just long enough to enter raw
+/// mode), so the borrow checker — not a doc comment — guarantees the terminal it restores through
+/// outlives the guard; [`Deref`]/[`DerefMut`] to the terminal mean this costs nothing at the call
+/// site, since `&mut guard` works anywhere `&mut Terminal<..>` is expected.
+///
+/// ```no_run
+/// # use ratatui::{backend::CrosstermBackend, terminal::{Terminal, TerminalGuard}};
+/// # fn run() -> std::io::Result<()> {
+/// let mut terminal = Terminal::new(CrosstermBackend::new(std::io::stdout()))?;
+/// let mut guard = TerminalGuard::new(&mut terminal)?;
+/// guard.draw(|frame| { /* ... */ })?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The panic-path restore is a hard constraint: a panic hook must be `'static`, so it cannot
+/// reach through this guard's borrow to the real writer, and instead always restores through
+/// `io::stdout()`. A [`TerminalGuard`] over a backend that doesn't write to stdout will leave the
+/// terminal in raw mode / the alternate screen if the process panics (the non-panicking
+/// [`Drop`] path is unaffected, and always restores through the real writer).
+pub struct TerminalGuard<'a, W: io::Write> {
+    terminal: &'a mut Terminal<CrosstermBackend<W>>,
+    /// The hook that was installed before this guard's, restored on [`Drop`] so that nesting or
+    /// recreating guards doesn't stack panic hooks indefinitely.
+    previous_hook: Arc<dyn Fn(&PanicHookInfo<'_>) + Sync + Send>,
+}
+
+impl<'a, W: io::Write> TerminalGuard<'a, W> {
+    /// Enters raw mode and the alternate screen, and installs a panic hook that restores the
+    /// terminal before delegating to whichever hook was previously installed.
+    pub fn new(terminal: &'a mut Terminal<CrosstermBackend<W>>) -> io::Result<Self> {
+        enable_raw_mode()?;
+        execute!(
+            terminal.backend_mut().writer_mut(),
+            EnterAlternateScreen,
+            crossterm::event::EnableMouseCapture
+        )?;
+
+        let previous_hook: Arc<dyn Fn(&PanicHookInfo<'_>) + Sync + Send> =
+            Arc::from(panic::take_hook());
+        let hook_for_panic = Arc::clone(&previous_hook);
+        panic::set_hook(Box::new(move |info: &PanicHookInfo<'_>| {
+            // See the hard constraint documented on `TerminalGuard` above: this can only ever
+            // restore a stdout-backed terminal, since a 'static hook can't reach through the
+            // guard's borrow to the backend's actual writer.
+            let _ = restore_terminal(&mut io::stdout());
+            hook_for_panic(info);
+        }));
+
+        Ok(Self {
+            terminal,
+            previous_hook,
+        })
+    }
+}
+
+impl<'a, W: io::Write> Deref for TerminalGuard<'a, W> {
+    type Target = Terminal<CrosstermBackend<W>>;
+
+    fn deref(&self) -> &Self::Target {
+        self.terminal
+    }
+}
+
+impl<'a, W: io::Write> DerefMut for TerminalGuard<'a, W> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.terminal
+    }
+}
+
+impl<'a, W: io::Write> Drop for TerminalGuard<'a, W> {
+    fn drop(&mut self) {
+        let _ = restore_terminal(self.terminal.backend_mut().writer_mut());
+        panic::set_hook(Box::new({
+            let previous_hook = Arc::clone(&self.previous_hook);
+            move |info: &PanicHookInfo<'_>| previous_hook(info)
+        }));
+    }
+}
+
+/// Leaves the alternate screen, disables raw mode and mouse capture, and shows the cursor
+/// again. Safe to call more than once (e.g. once from a panic hook, once from [`Drop`]).
+fn restore_terminal<W: io::Write>(writer: &mut W) -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        writer,
+        LeaveAlternateScreen,
+        crossterm::event::DisableMouseCapture,
+        crossterm::cursor::Show
+    )
+}
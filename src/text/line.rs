@@ -0,0 +1,40 @@
+use crate::text::Span;
+
+/// A single line of text, made up of one or more [`Span`]s.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Line<'a> {
+    pub spans: Vec<Span<'a>>,
+}
+
+impl<'a> Line<'a> {
+    pub fn raw<T>(content: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        Self {
+            spans: vec![content.into()],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.spans.iter().map(Span::width).sum()
+    }
+}
+
+impl<'a> From<&'a str> for Line<'a> {
+    fn from(content: &'a str) -> Self {
+        Line::raw(content)
+    }
+}
+
+impl From<String> for Line<'static> {
+    fn from(content: String) -> Self {
+        Line::raw(content)
+    }
+}
+
+impl<'a> From<Span<'a>> for Line<'a> {
+    fn from(span: Span<'a>) -> Self {
+        Self { spans: vec![span] }
+    }
+}
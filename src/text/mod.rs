@@ -0,0 +1,10 @@
+//! Styled text: [`Span`] < [`Line`] < [`Text`].
+
+mod line;
+mod span;
+#[allow(clippy::module_inception)]
+mod text;
+
+pub use line::Line;
+pub use span::Span;
+pub use text::Text;
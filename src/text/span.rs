@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+
+use crate::style::Style;
+
+/// A string of text with a single style and an optional OSC 8 hyperlink target.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Span<'a> {
+    pub content: Cow<'a, str>,
+    pub style: Style,
+    pub url: Option<Cow<'a, str>>,
+}
+
+impl<'a> Span<'a> {
+    pub fn raw<T>(content: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            content: content.into(),
+            style: Style::new(),
+            url: None,
+        }
+    }
+
+    pub fn styled<T>(content: T, style: Style) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            content: content.into(),
+            style,
+            url: None,
+        }
+    }
+
+    /// Creates a span that terminals supporting [OSC 8] will render as a clickable hyperlink.
+    ///
+    /// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+    pub fn hyperlink<T, U>(content: T, url: U) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self {
+            content: content.into(),
+            style: Style::new(),
+            url: Some(url.into()),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.content.chars().count()
+    }
+}
+
+impl<'a> From<&'a str> for Span<'a> {
+    fn from(content: &'a str) -> Self {
+        Span::raw(content)
+    }
+}
+
+impl From<String> for Span<'static> {
+    fn from(content: String) -> Self {
+        Span::raw(content)
+    }
+}
@@ -0,0 +1,60 @@
+use std::borrow::Cow;
+
+use crate::text::{Line, Span};
+
+/// Multiple lines of styled text.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Text<'a> {
+    pub lines: Vec<Line<'a>>,
+}
+
+impl<'a> Text<'a> {
+    pub fn raw<T>(content: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let content: Cow<'a, str> = content.into();
+        let lines = match content {
+            Cow::Borrowed(s) => s.lines().map(Line::from).collect(),
+            Cow::Owned(s) => s.lines().map(|l| Line::from(l.to_string())).collect(),
+        };
+        Self { lines }
+    }
+
+    /// The number of lines this text spans once rendered.
+    pub fn height(&self) -> usize {
+        self.lines.len()
+    }
+}
+
+impl<'a> From<&'a str> for Text<'a> {
+    fn from(content: &'a str) -> Self {
+        Text::raw(content)
+    }
+}
+
+impl From<String> for Text<'static> {
+    fn from(content: String) -> Self {
+        Text::raw(content)
+    }
+}
+
+impl<'a> From<Span<'a>> for Text<'a> {
+    fn from(span: Span<'a>) -> Self {
+        Self {
+            lines: vec![Line::from(span)],
+        }
+    }
+}
+
+impl<'a> From<Line<'a>> for Text<'a> {
+    fn from(line: Line<'a>) -> Self {
+        Self { lines: vec![line] }
+    }
+}
+
+impl<'a> From<Vec<Line<'a>>> for Text<'a> {
+    fn from(lines: Vec<Line<'a>>) -> Self {
+        Self { lines }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::Widget,
+};
+
+bitflags::bitflags! {
+    /// Which sides of a [`Block`] to draw a border on.
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct Borders: u8 {
+        const NONE = 0b0000;
+        const TOP = 0b0001;
+        const RIGHT = 0b0010;
+        const BOTTOM = 0b0100;
+        const LEFT = 0b1000;
+        const ALL = Self::TOP.bits() | Self::RIGHT.bits() | Self::BOTTOM.bits() | Self::LEFT.bits();
+    }
+}
+
+/// The line style used to draw a [`Block`]'s border.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BorderType {
+    #[default]
+    Plain,
+    Rounded,
+    Double,
+    Thick,
+}
+
+impl BorderType {
+    const fn symbols(self) -> (&'static str, &'static str, &'static str) {
+        // (horizontal, vertical, corner) — `corner` is drawn at the top-left corner only; the
+        // other corners keep the side glyphs drawn over them.
+        match self {
+            BorderType::Plain => ("─", "│", "┌"),
+            BorderType::Rounded => ("─", "│", "╭"),
+            BorderType::Double => ("═", "║", "╔"),
+            BorderType::Thick => ("━", "┃", "┏"),
+        }
+    }
+}
+
+/// A rectangular border drawn around some content. No interior padding is applied beyond the
+/// one cell taken by each bordered side.
+#[derive(Debug, Default, Clone)]
+pub struct Block {
+    borders: Borders,
+    border_style: Style,
+    border_type: BorderType,
+    style: Style,
+}
+
+impl Block {
+    pub fn borders(mut self, borders: Borders) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    pub fn border_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = border_type;
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The area remaining for content once this block's borders are drawn, i.e. `area` shrunk
+    /// by one cell on every bordered side.
+    pub fn inner(&self, area: Rect) -> Rect {
+        let left = u16::from(self.borders.contains(Borders::LEFT));
+        let right = u16::from(self.borders.contains(Borders::RIGHT));
+        let top = u16::from(self.borders.contains(Borders::TOP));
+        let bottom = u16::from(self.borders.contains(Borders::BOTTOM));
+        Rect::new(
+            area.x.saturating_add(left),
+            area.y.saturating_add(top),
+            area.width.saturating_sub(left + right),
+            area.height.saturating_sub(top + bottom),
+        )
+    }
+}
+
+impl Widget for Block {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+
+        let (h, v, corner) = self.border_type.symbols();
+        if self.borders.contains(Borders::TOP) {
+            for x in area.left()..area.right() {
+                let cell = buf.get_mut(x, area.top());
+                cell.set_symbol(h).set_style(self.border_style);
+            }
+        }
+        if self.borders.contains(Borders::BOTTOM) && area.height > 1 {
+            for x in area.left()..area.right() {
+                let cell = buf.get_mut(x, area.bottom() - 1);
+                cell.set_symbol(h).set_style(self.border_style);
+            }
+        }
+        if self.borders.contains(Borders::LEFT) {
+            for y in area.top()..area.bottom() {
+                let cell = buf.get_mut(area.left(), y);
+                cell.set_symbol(v).set_style(self.border_style);
+            }
+        }
+        if self.borders.contains(Borders::RIGHT) && area.width > 1 {
+            for y in area.top()..area.bottom() {
+                let cell = buf.get_mut(area.right() - 1, y);
+                cell.set_symbol(v).set_style(self.border_style);
+            }
+        }
+        if self.borders.contains(Borders::TOP | Borders::LEFT) {
+            buf.get_mut(area.left(), area.top())
+                .set_symbol(corner)
+                .set_style(self.border_style);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Widgets that can be rendered into a [`crate::buffer::Buffer`].
+
+mod block;
+mod paragraph;
+mod scrollbar;
+pub mod table;
+
+pub use block::{BorderType, Borders, Block};
+pub use paragraph::Paragraph;
+pub use scrollbar::{Scrollbar, ScrollbarOrientation, ScrollbarState};
+pub use table::{Cell, HighlightSpacing, Row, Table, TableState};
+
+use crate::{buffer::Buffer, layout::Rect};
+
+/// A widget that consumes itself to draw into a region of a [`Buffer`].
+pub trait Widget {
+    fn render(self, area: Rect, buf: &mut Buffer);
+}
+
+/// A widget whose rendering depends on (and may update) some external `State`.
+pub trait StatefulWidget {
+    type State;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State);
+}
@@ -0,0 +1,84 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::Text,
+    widgets::{Block, Widget},
+};
+
+/// How a [`Paragraph`]'s lines are positioned within its area along the horizontal axis.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+enum Alignment {
+    #[default]
+    Left,
+    Center,
+}
+
+/// A widget that draws (optionally centered) text inside an optional [`Block`]. Lines wider than
+/// the area are truncated; they are not wrapped.
+#[derive(Debug, Default, Clone)]
+pub struct Paragraph<'a> {
+    text: Text<'a>,
+    style: Style,
+    alignment: Alignment,
+    block: Option<Block>,
+}
+
+impl<'a> Paragraph<'a> {
+    pub fn new<T>(text: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            text: text.into(),
+            style: Style::new(),
+            alignment: Alignment::default(),
+            block: None,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn centered(mut self) -> Self {
+        self.alignment = Alignment::Center;
+        self
+    }
+
+    pub fn block(mut self, block: Block) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl<'a> Widget for Paragraph<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = match &self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.clone().render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        buf.set_style(inner, self.style);
+
+        for (y, line) in (inner.top()..inner.bottom()).zip(self.text.lines.iter()) {
+            let width = line.width() as u16;
+            let x = match self.alignment {
+                Alignment::Left => inner.left(),
+                Alignment::Center => inner
+                    .left()
+                    .saturating_add(inner.width.saturating_sub(width) / 2),
+            };
+            let mut cx = x;
+            for span in &line.spans {
+                let mut span = span.clone();
+                span.style = self.style.patch(span.style);
+                cx = buf.set_span(cx, y, &span, inner.right().saturating_sub(cx));
+            }
+        }
+    }
+}
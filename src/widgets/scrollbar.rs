@@ -0,0 +1,108 @@
+use crate::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+
+/// Which edge of its area a [`Scrollbar`] is drawn along.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ScrollbarOrientation {
+    #[default]
+    VerticalRight,
+    VerticalLeft,
+    HorizontalBottom,
+    HorizontalTop,
+}
+
+/// State of a [`Scrollbar`]: the total scrollable length and the current position within it.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct ScrollbarState {
+    content_length: usize,
+    position: usize,
+}
+
+impl ScrollbarState {
+    pub fn new(content_length: usize) -> Self {
+        Self {
+            content_length,
+            position: 0,
+        }
+    }
+
+    pub fn position(mut self, position: usize) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn content_length(mut self, content_length: usize) -> Self {
+        self.content_length = content_length;
+        self
+    }
+}
+
+/// A scroll indicator, typically drawn alongside a list/table to show how far the viewport has
+/// scrolled through the content.
+#[derive(Debug, Default, Clone)]
+pub struct Scrollbar {
+    orientation: ScrollbarOrientation,
+    begin_symbol: Option<&'static str>,
+    end_symbol: Option<&'static str>,
+}
+
+impl Scrollbar {
+    pub fn orientation(mut self, orientation: ScrollbarOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn begin_symbol(mut self, symbol: Option<&'static str>) -> Self {
+        self.begin_symbol = symbol;
+        self
+    }
+
+    pub fn end_symbol(mut self, symbol: Option<&'static str>) -> Self {
+        self.end_symbol = symbol;
+        self
+    }
+}
+
+impl StatefulWidget for Scrollbar {
+    type State = ScrollbarState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let vertical = matches!(
+            self.orientation,
+            ScrollbarOrientation::VerticalLeft | ScrollbarOrientation::VerticalRight
+        );
+        let track_len = if vertical { area.height } else { area.width };
+        if track_len == 0 || state.content_length == 0 {
+            return;
+        }
+
+        let x = match self.orientation {
+            ScrollbarOrientation::VerticalLeft => area.left(),
+            _ => area.right().saturating_sub(1),
+        };
+        let y = match self.orientation {
+            ScrollbarOrientation::HorizontalTop => area.top(),
+            _ => area.bottom().saturating_sub(1),
+        };
+
+        let thumb_offset = (state.position * track_len as usize / state.content_length.max(1))
+            .min(track_len.saturating_sub(1) as usize) as u16;
+
+        for i in 0..track_len {
+            let (cx, cy) = if vertical {
+                (x, area.top() + i)
+            } else {
+                (area.left() + i, y)
+            };
+            let symbol = if let Some(symbol) = self.begin_symbol.filter(|_| i == 0) {
+                symbol
+            } else if let Some(symbol) = self.end_symbol.filter(|_| i == track_len - 1) {
+                symbol
+            } else if i == thumb_offset {
+                "█"
+            } else {
+                "│"
+            };
+            buf.get_mut(cx, cy).set_symbol(symbol);
+        }
+    }
+}
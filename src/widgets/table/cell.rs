@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+
+use crate::{
+    layout::VerticalAlignment,
+    style::Style,
+    text::{Span, Text},
+};
+
+/// A single cell of a [`Row`](super::Row) in a [`Table`](super::Table).
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Cell<'a> {
+    content: Text<'a>,
+    style: Style,
+    vertical_alignment: Option<VerticalAlignment>,
+}
+
+impl<'a> Cell<'a> {
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            content: content.into(),
+            style: Style::new(),
+            vertical_alignment: None,
+        }
+    }
+
+    /// Creates a cell whose content is a single clickable [OSC 8] hyperlink.
+    ///
+    /// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+    pub fn hyperlink<T, U>(content: T, url: U) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::new(Text::from(Span::hyperlink(content, url)))
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets how this cell's content is positioned when it spans fewer lines than the row's
+    /// height. Overrides the [`Row`](super::Row)'s alignment for this cell only.
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = Some(alignment);
+        self
+    }
+
+    pub fn content(&self) -> &Text<'a> {
+        &self.content
+    }
+
+    pub fn style_ref(&self) -> Style {
+        self.style
+    }
+
+    pub fn vertical_alignment_ref(&self) -> Option<VerticalAlignment> {
+        self.vertical_alignment
+    }
+}
+
+impl<'a, T> From<T> for Cell<'a>
+where
+    T: Into<Text<'a>>,
+{
+    fn from(content: T) -> Self {
+        Self::new(content)
+    }
+}
@@ -0,0 +1,311 @@
+//! A [`Table`] widget for displaying data in a tabular form.
+
+mod cell;
+mod row;
+mod state;
+
+pub use cell::Cell;
+pub use row::Row;
+pub use state::TableState;
+
+use crate::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::Text,
+    widgets::StatefulWidget,
+};
+
+/// Controls when the column reserved for [`Table::highlight_symbol`] is drawn.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum HighlightSpacing {
+    /// Always reserve space for the highlight symbol, even when nothing is selected.
+    Always,
+    /// Only reserve space for the highlight symbol while a row is selected.
+    #[default]
+    WhenSelected,
+    /// Never reserve space for the highlight symbol.
+    Never,
+}
+
+/// A widget to display data in formatted rows and columns, with an optional header.
+#[derive(Debug, Clone, Default)]
+pub struct Table<'a> {
+    rows: Vec<Row<'a>>,
+    header: Option<Row<'a>>,
+    widths: Vec<Constraint>,
+    style: Style,
+    highlight_style: Style,
+    highlight_symbol: Text<'a>,
+    highlight_spacing: HighlightSpacing,
+    bg: Option<Color>,
+}
+
+impl<'a> Table<'a> {
+    pub fn new<R, W>(rows: R, widths: W) -> Self
+    where
+        R: IntoIterator<Item = Row<'a>>,
+        W: IntoIterator,
+        W::Item: Into<Constraint>,
+    {
+        Self {
+            rows: rows.into_iter().collect(),
+            header: None,
+            widths: widths.into_iter().map(Into::into).collect(),
+            style: Style::new(),
+            highlight_style: Style::new(),
+            highlight_symbol: Text::default(),
+            highlight_spacing: HighlightSpacing::default(),
+            bg: None,
+        }
+    }
+
+    pub fn header(mut self, header: Row<'a>) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    pub fn highlight_symbol<T>(mut self, symbol: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        self.highlight_symbol = symbol.into();
+        self
+    }
+
+    pub fn highlight_spacing(mut self, spacing: HighlightSpacing) -> Self {
+        self.highlight_spacing = spacing;
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// The width of the column reserved for [`Self::highlight_symbol`], given whether a row is
+    /// currently selected.
+    fn selection_width(&self, any_selected: bool) -> u16 {
+        let reserve = match self.highlight_spacing {
+            HighlightSpacing::Always => true,
+            HighlightSpacing::WhenSelected => any_selected,
+            HighlightSpacing::Never => false,
+        };
+        if reserve {
+            self.highlight_symbol
+                .lines
+                .iter()
+                .map(|line| line.width() as u16)
+                .max()
+                .unwrap_or(0)
+        } else {
+            0
+        }
+    }
+
+    /// Computes the `(offset, selected)` window actually rendered for `area`, taking the
+    /// `state`'s scroll padding into account.
+    ///
+    /// This walks cumulative row heights (rather than assuming a uniform height) so that it
+    /// works for tables whose rows span a varying number of lines.
+    pub fn visible_rows(&self, area: Rect, state: &TableState) -> (usize, usize) {
+        let rows = &self.rows;
+        if rows.is_empty() {
+            return (0, 0);
+        }
+
+        let max_height = area
+            .height
+            .saturating_sub(self.header.as_ref().map_or(0, Row::height_ref));
+
+        let selected = state.selected().unwrap_or(0).min(rows.len() - 1);
+        // Padding can never exceed half the viewport, or it could never be satisfied.
+        let max_padding = (max_height as usize / 2).min(rows.len());
+        let padding = state.scroll_padding().min(max_padding);
+
+        let mut offset = state.offset().min(rows.len().saturating_sub(1));
+
+        // Lower the naive offset (scroll up) so that `padding` rows of context are visible above
+        // the selection, without scrolling past row 0.
+        if selected.saturating_sub(padding) < offset {
+            offset = selected.saturating_sub(padding);
+        }
+
+        // Then shrink the window from the top until the selection plus its bottom padding fits
+        // within `max_height`, walking the actual per-row heights rather than assuming they are
+        // uniform.
+        loop {
+            let last_visible = selected + padding.min(rows.len() - 1 - selected);
+            let height: usize = rows[offset..=last_visible.max(offset)]
+                .iter()
+                .map(|row| row.height_ref() as usize)
+                .sum();
+            if height <= max_height as usize || offset >= selected {
+                break;
+            }
+            offset += 1;
+        }
+
+        (offset, selected)
+    }
+
+    /// Returns the number of blank lines to draw above `cell`'s text so that it lands at its
+    /// resolved [`VerticalAlignment`](crate::layout::VerticalAlignment) within a row of
+    /// `row_height` lines.
+    pub(crate) fn cell_top_padding(row: &Row<'a>, cell: &Cell<'a>, row_height: u16) -> u16 {
+        let content_height = cell.content().height().min(row_height as usize) as u16;
+        row.cell_vertical_alignment(cell)
+            .top_padding(row_height, content_height)
+    }
+
+    fn render_row(&self, row: &Row<'a>, area: Rect, buf: &mut Buffer, selected: bool) {
+        let style = if selected {
+            row.style_ref().patch(self.highlight_style)
+        } else {
+            row.style_ref()
+        };
+        buf.set_style(area, style);
+
+        let columns = Layout::new(Direction::Horizontal, self.widths.clone()).split(area);
+        for (cell, column) in row.cells().iter().zip(columns) {
+            let top_padding = Self::cell_top_padding(row, cell, column.height);
+            let lines_top = column.top().saturating_add(top_padding);
+            for (y, line) in (lines_top..column.bottom()).zip(cell.content().lines.iter()) {
+                let mut cx = column.left();
+                for span in &line.spans {
+                    let mut span = span.clone();
+                    span.style = style.patch(span.style);
+                    cx = buf.set_span(cx, y, &span, column.right().saturating_sub(cx));
+                }
+            }
+        }
+    }
+}
+
+impl<'a> StatefulWidget for Table<'a> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.clear_row_areas();
+        if area.width == 0 || area.height == 0 || self.rows.is_empty() {
+            return;
+        }
+        if let Some(bg) = self.bg {
+            buf.set_style(area, Style::new().bg(bg));
+        }
+
+        let (offset, _) = self.visible_rows(area, state);
+        *state.offset_mut() = offset;
+
+        let selection_width = self.selection_width(state.selected().is_some());
+        let content_area = Rect::new(
+            area.x.saturating_add(selection_width),
+            area.y,
+            area.width.saturating_sub(selection_width),
+            area.height,
+        );
+
+        let mut y = area.top();
+        if let Some(header) = &self.header {
+            let header_area = Rect::new(content_area.x, y, content_area.width, header.height_ref());
+            self.render_row(header, header_area, buf, false);
+            y = y.saturating_add(header.height_ref());
+        }
+
+        for (index, row) in self.rows.iter().enumerate().skip(offset) {
+            if y >= area.bottom() {
+                break;
+            }
+            let height = row.height_ref().min(area.bottom().saturating_sub(y));
+            let is_selected = Some(index) == state.selected();
+            let row_area = Rect::new(content_area.x, y, content_area.width, height);
+            self.render_row(row, row_area, buf, is_selected);
+            state.record_row_area(index, Rect::new(area.x, y, area.width, height));
+
+            if is_selected && selection_width > 0 {
+                let symbol_area = Rect::new(area.x, y, selection_width, height);
+                for (line_y, line) in (symbol_area.top()..symbol_area.bottom()).zip(&self.highlight_symbol.lines) {
+                    let mut cx = symbol_area.left();
+                    for span in &line.spans {
+                        let mut span = span.clone();
+                        span.style = self.highlight_style.patch(span.style);
+                        cx = buf.set_span(cx, line_y, &span, symbol_area.right().saturating_sub(cx));
+                    }
+                }
+            }
+
+            y = y.saturating_add(height);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{layout::VerticalAlignment, widgets::table::{Cell, Row}};
+
+    fn table(row_count: usize) -> Table<'static> {
+        let rows = (0..row_count).map(|i| Row::new([i.to_string()]));
+        Table::new(rows, [Constraint::Min(1)])
+    }
+
+    #[test]
+    fn visible_rows_keeps_top_of_list_pinned() {
+        let t = table(10);
+        let state = TableState::new().with_selected(0).with_scroll_padding(2);
+        let area = Rect::new(0, 0, 10, 4);
+        let (offset, selected) = t.visible_rows(area, &state);
+        assert_eq!(offset, 0, "selection near the top should not scroll past row 0");
+        assert_eq!(selected, 0);
+    }
+
+    #[test]
+    fn visible_rows_keeps_bottom_of_list_pinned() {
+        let t = table(10);
+        let state = TableState::new().with_selected(9).with_scroll_padding(2);
+        let area = Rect::new(0, 0, 10, 4);
+        let (offset, selected) = t.visible_rows(area, &state);
+        assert_eq!(selected, 9);
+        // The viewport can't show 2 rows of padding below the last row, so it should scroll as
+        // far down as it can rather than try (and fail) to honor the padding exactly.
+        assert_eq!(offset, 6);
+    }
+
+    #[test]
+    fn visible_rows_scrolls_to_honor_padding_in_the_middle() {
+        let t = table(20);
+        let state = TableState::new().with_selected(10).with_scroll_padding(2);
+        let area = Rect::new(0, 0, 10, 4);
+        let (offset, selected) = t.visible_rows(area, &state);
+        assert_eq!(selected, 10);
+        // A 4-row viewport can't fit 2 rows of padding on both sides of the selection; the
+        // window scrolls down just far enough to show the selection and the 2 rows below it.
+        assert_eq!(offset, 9, "selection should keep 2 rows of padding below it");
+    }
+
+    #[test]
+    fn cell_top_padding_matches_vertical_alignment() {
+        let cell = Cell::new("x");
+
+        let row = Row::new([cell.clone()]).height(5);
+        assert_eq!(Table::cell_top_padding(&row, &cell, 5), 0);
+
+        let row = row.vertical_alignment(VerticalAlignment::Center);
+        assert_eq!(Table::cell_top_padding(&row, &cell, 5), 2);
+
+        let row = Row::new([cell.clone()])
+            .height(5)
+            .vertical_alignment(VerticalAlignment::Bottom);
+        assert_eq!(Table::cell_top_padding(&row, &cell, 5), 4);
+    }
+}
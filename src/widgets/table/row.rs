@@ -0,0 +1,70 @@
+use crate::{layout::VerticalAlignment, style::Style, widgets::table::Cell};
+
+/// A single row of a [`Table`](super::Table), made up of [`Cell`]s and a fixed height.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Row<'a> {
+    cells: Vec<Cell<'a>>,
+    height: u16,
+    style: Style,
+    vertical_alignment: VerticalAlignment,
+}
+
+impl<'a> Row<'a> {
+    pub fn new<T>(cells: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Cell<'a>>,
+    {
+        Self {
+            cells: cells.into_iter().map(Into::into).collect(),
+            height: 1,
+            style: Style::new(),
+            vertical_alignment: VerticalAlignment::default(),
+        }
+    }
+
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the default vertical alignment for every cell in this row; a cell can override it
+    /// via [`Cell::vertical_alignment`].
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    pub fn cells(&self) -> &[Cell<'a>] {
+        &self.cells
+    }
+
+    /// The height, in terminal rows, that this row occupies once rendered.
+    pub fn height_ref(&self) -> u16 {
+        self.height
+    }
+
+    pub fn style_ref(&self) -> Style {
+        self.style
+    }
+
+    /// Resolves the effective vertical alignment for `cell`, falling back to the row's own
+    /// alignment when the cell doesn't specify one.
+    pub fn cell_vertical_alignment(&self, cell: &Cell<'a>) -> VerticalAlignment {
+        cell.vertical_alignment_ref().unwrap_or(self.vertical_alignment)
+    }
+}
+
+impl<'a, Item> FromIterator<Item> for Row<'a>
+where
+    Item: Into<Cell<'a>>,
+{
+    fn from_iter<T: IntoIterator<Item = Item>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
@@ -0,0 +1,145 @@
+use crate::layout::Rect;
+
+/// State of a [`Table`](super::Table) widget: which row is selected, scrolled, and how much
+/// context (scroll padding) to keep around the selection.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct TableState {
+    pub(crate) offset: usize,
+    pub(crate) selected: Option<usize>,
+    pub(crate) scroll_padding: usize,
+    /// The screen area of each currently visible data row, keyed by row index. Repopulated on
+    /// every `render_stateful_widget` call so it always reflects the last rendered layout.
+    pub(crate) row_areas: Vec<(usize, Rect)>,
+}
+
+impl TableState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_selected(mut self, selected: impl Into<Option<usize>>) -> Self {
+        self.selected = selected.into();
+        self
+    }
+
+    /// Sets the minimum number of rows that must remain visible above and below the selected
+    /// row, akin to a text editor's "scrolloff"/"scroll padding" setting. The padding is capped
+    /// at half the viewport's height, and is truncated near the first and last rows; when the
+    /// viewport is too short to fit it on both sides, the rows below the selection take
+    /// precedence.
+    pub fn with_scroll_padding(mut self, padding: usize) -> Self {
+        self.scroll_padding = padding;
+        self
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    pub fn selected_mut(&mut self) -> &mut Option<usize> {
+        &mut self.selected
+    }
+
+    pub fn scroll_padding(&self) -> usize {
+        self.scroll_padding
+    }
+
+    pub fn set_scroll_padding(&mut self, padding: usize) {
+        self.scroll_padding = padding;
+    }
+
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+        if index.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Records `area` as the screen position of `row`, replacing whatever was recorded for it
+    /// during this render. Called by [`Table`](super::Table) while rendering.
+    pub(crate) fn record_row_area(&mut self, row: usize, area: Rect) {
+        self.row_areas.retain(|(index, _)| *index != row);
+        self.row_areas.push((row, area));
+    }
+
+    /// Clears the previously recorded row areas; called at the start of each render.
+    pub(crate) fn clear_row_areas(&mut self) {
+        self.row_areas.clear();
+    }
+
+    /// Returns the index of the data row rendered at the given screen position, if any.
+    pub fn row_at_position(&self, column: u16, row: u16) -> Option<usize> {
+        self.row_areas
+            .iter()
+            .find(|(_, area)| {
+                column >= area.left()
+                    && column < area.right()
+                    && row >= area.top()
+                    && row < area.bottom()
+            })
+            .map(|(index, _)| *index)
+    }
+
+    /// Selects the row at the given screen position, if any; a convenience wrapper around
+    /// [`row_at_position`](Self::row_at_position) and [`select`](Self::select).
+    pub fn select_at_position(&mut self, column: u16, row: u16) {
+        if let Some(index) = self.row_at_position(column, row) {
+            self.select(Some(index));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_at_position_finds_the_recorded_row() {
+        let mut state = TableState::new();
+        state.record_row_area(0, Rect::new(0, 1, 10, 1));
+        state.record_row_area(1, Rect::new(0, 2, 10, 1));
+        state.record_row_area(2, Rect::new(0, 3, 10, 1));
+
+        assert_eq!(state.row_at_position(4, 2), Some(1));
+        assert_eq!(state.row_at_position(4, 0), None, "above the first recorded row");
+        assert_eq!(state.row_at_position(4, 4), None, "below the last recorded row");
+    }
+
+    #[test]
+    fn select_at_position_selects_the_clicked_row() {
+        let mut state = TableState::new();
+        state.record_row_area(0, Rect::new(0, 1, 10, 1));
+        state.record_row_area(1, Rect::new(0, 2, 10, 1));
+
+        state.select_at_position(4, 2);
+        assert_eq!(state.selected(), Some(1));
+
+        state.select_at_position(4, 50);
+        assert_eq!(
+            state.selected(),
+            Some(1),
+            "a click outside every recorded row should leave the selection untouched"
+        );
+    }
+
+    #[test]
+    fn clear_row_areas_drops_stale_hit_test_data() {
+        let mut state = TableState::new();
+        state.record_row_area(0, Rect::new(0, 1, 10, 1));
+        state.clear_row_areas();
+        assert_eq!(state.row_at_position(4, 1), None);
+    }
+}